@@ -0,0 +1,33 @@
+use core::marker::Unpin;
+use core::mem::PinMut;
+use futures_core::future::{Future, TryFuture};
+use futures_core::task::{self, Poll};
+
+/// Future for the `into_future` combinator, which turns a `TryFuture` into
+/// a plain `Future` whose output is a `Result`.
+///
+/// This structure is returned by the
+/// [`into_future`](super::TryFutureExt::into_future) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct IntoFuture<Fut> {
+    future: Fut,
+}
+
+impl<Fut: Unpin> Unpin for IntoFuture<Fut> {}
+
+impl<Fut> IntoFuture<Fut> {
+    unsafe_pinned!(future: Fut);
+
+    pub(super) fn new(future: Fut) -> IntoFuture<Fut> {
+        IntoFuture { future }
+    }
+}
+
+impl<Fut: TryFuture> Future for IntoFuture<Fut> {
+    type Output = Result<Fut::Ok, Fut::Error>;
+
+    fn poll(mut self: PinMut<Self>, cx: &mut task::Context) -> Poll<Self::Output> {
+        self.future().try_poll(cx)
+    }
+}
@@ -0,0 +1,29 @@
+//! Futures
+//!
+//! This module contains a number of functions for working with `Future`s,
+//! including the `TryFutureExt` trait which adds useful adapters for
+//! `Future`s that return `Result`s.
+
+use futures_core::future::TryFuture;
+
+mod into_future;
+pub use self::into_future::IntoFuture;
+
+impl<Fut: ?Sized> TryFutureExt for Fut where Fut: TryFuture {}
+
+/// Adapters specific to `Result`-returning futures
+pub trait TryFutureExt: TryFuture {
+    /// Wraps a `TryFuture` into a type that implements `Future`.
+    ///
+    /// `TryFuture`s currently do not implement the `Future` trait due to
+    /// limitations of the compiler, so bounds like `Fut: Future` aren't
+    /// satisfied by a plain `TryFuture`. `into_future` bridges this gap
+    /// with an adapter whose `Output` is `Result<Self::Ok, Self::Error>`,
+    /// so it can be used anywhere a `Future` is required, such as with
+    /// `FuturesUnordered`.
+    fn into_future(self) -> IntoFuture<Self>
+        where Self: Sized,
+    {
+        IntoFuture::new(self)
+    }
+}
@@ -0,0 +1,107 @@
+use core::marker::Unpin;
+use core::mem::PinMut;
+use futures_core::future::TryFuture;
+use futures_core::stream::{FusedStream, Stream, TryStream};
+use futures_core::task::{self, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+
+/// A stream that runs a fallible future for each `Ok` item, yielding the
+/// mapped value produced by that future.
+///
+/// This structure is returned by the
+/// [`and_then`](super::TryStreamExt::and_then) method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct AndThen<St, Fut, F> {
+    stream: St,
+    f: F,
+    pending: Option<Fut>,
+}
+
+impl<St, Fut, F> Unpin for AndThen<St, Fut, F>
+    where St: Unpin, Fut: Unpin,
+{}
+
+impl<St, Fut, F> AndThen<St, Fut, F> {
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_pinned!(pending: Option<Fut>);
+
+    pub(super) fn new(stream: St, f: F) -> Self {
+        AndThen { stream, f, pending: None }
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, Fut, F, Item> Sink<Item> for AndThen<S, Fut, F>
+    where S: TryStream + Sink<Item>,
+{
+    type SinkError = S::SinkError;
+
+    fn poll_ready(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.stream().poll_ready(cx)
+    }
+
+    fn start_send(
+        mut self: PinMut<Self>,
+        item: Item,
+    ) -> Result<(), Self::SinkError> {
+        self.stream().start_send(item)
+    }
+
+    fn poll_flush(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.stream().poll_flush(cx)
+    }
+
+    fn poll_close(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.stream().poll_close(cx)
+    }
+}
+
+impl<St, Fut, F> FusedStream for AndThen<St, Fut, F>
+    where St: TryStream + FusedStream,
+          F: FnMut(St::Ok) -> Fut,
+          Fut: TryFuture<Error = St::Error>,
+{
+    fn is_terminated(&self) -> bool {
+        self.pending.is_none() && self.stream.is_terminated()
+    }
+}
+
+impl<St, Fut, F> Stream for AndThen<St, Fut, F>
+    where St: TryStream,
+          F: FnMut(St::Ok) -> Fut,
+          Fut: TryFuture<Error = St::Error>,
+{
+    type Item = Result<Fut::Ok, St::Error>;
+
+    fn poll_next(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Option<Self::Item>> {
+        if self.pending().as_pin_mut().is_none() {
+            let item = match ready!(self.stream().try_poll_next(cx)) {
+                Some(Ok(x)) => x,
+                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+                None => return Poll::Ready(None),
+            };
+            let fut = (self.f())(item);
+            PinMut::set(self.pending(), Some(fut));
+        }
+
+        let result = ready!(self.pending().as_pin_mut().unwrap().try_poll(cx));
+        PinMut::set(self.pending(), None);
+        Poll::Ready(Some(result))
+    }
+}
@@ -0,0 +1,93 @@
+use core::marker::Unpin;
+use core::mem::PinMut;
+use futures_core::stream::{FusedStream, Stream, TryStream};
+use futures_core::task::{self, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+
+/// A stream that inspects each `Err` value before passing it on.
+///
+/// This structure is returned by the
+/// [`inspect_err`](super::TryStreamExt::inspect_err) method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct InspectErr<St, F> {
+    stream: St,
+    f: F,
+}
+
+impl<St, F> Unpin for InspectErr<St, F>
+    where St: Unpin,
+{}
+
+impl<St, F> InspectErr<St, F> {
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+
+    pub(super) fn new(stream: St, f: F) -> Self {
+        InspectErr { stream, f }
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, F, Item> Sink<Item> for InspectErr<S, F>
+    where S: TryStream + Sink<Item>,
+{
+    type SinkError = S::SinkError;
+
+    fn poll_ready(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.stream().poll_ready(cx)
+    }
+
+    fn start_send(
+        mut self: PinMut<Self>,
+        item: Item,
+    ) -> Result<(), Self::SinkError> {
+        self.stream().start_send(item)
+    }
+
+    fn poll_flush(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.stream().poll_flush(cx)
+    }
+
+    fn poll_close(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.stream().poll_close(cx)
+    }
+}
+
+impl<St, F> FusedStream for InspectErr<St, F>
+    where St: TryStream + FusedStream,
+          F: FnMut(&St::Error),
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, F> Stream for InspectErr<St, F>
+    where St: TryStream,
+          F: FnMut(&St::Error),
+{
+    type Item = Result<St::Ok, St::Error>;
+
+    fn poll_next(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Option<Self::Item>> {
+        let item = ready!(self.stream().try_poll_next(cx));
+        if let Some(Err(ref e)) = item {
+            (self.f())(e);
+        }
+        Poll::Ready(item)
+    }
+}
@@ -0,0 +1,93 @@
+use core::marker::Unpin;
+use core::mem::PinMut;
+use futures_core::stream::{FusedStream, Stream, TryStream};
+use futures_core::task::{self, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+
+/// A stream that inspects each `Ok` value before passing it on.
+///
+/// This structure is returned by the
+/// [`inspect_ok`](super::TryStreamExt::inspect_ok) method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct InspectOk<St, F> {
+    stream: St,
+    f: F,
+}
+
+impl<St, F> Unpin for InspectOk<St, F>
+    where St: Unpin,
+{}
+
+impl<St, F> InspectOk<St, F> {
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+
+    pub(super) fn new(stream: St, f: F) -> Self {
+        InspectOk { stream, f }
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, F, Item> Sink<Item> for InspectOk<S, F>
+    where S: TryStream + Sink<Item>,
+{
+    type SinkError = S::SinkError;
+
+    fn poll_ready(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.stream().poll_ready(cx)
+    }
+
+    fn start_send(
+        mut self: PinMut<Self>,
+        item: Item,
+    ) -> Result<(), Self::SinkError> {
+        self.stream().start_send(item)
+    }
+
+    fn poll_flush(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.stream().poll_flush(cx)
+    }
+
+    fn poll_close(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.stream().poll_close(cx)
+    }
+}
+
+impl<St, F> FusedStream for InspectOk<St, F>
+    where St: TryStream + FusedStream,
+          F: FnMut(&St::Ok),
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, F> Stream for InspectOk<St, F>
+    where St: TryStream,
+          F: FnMut(&St::Ok),
+{
+    type Item = Result<St::Ok, St::Error>;
+
+    fn poll_next(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Option<Self::Item>> {
+        let item = ready!(self.stream().try_poll_next(cx));
+        if let Some(Ok(ref x)) = item {
+            (self.f())(x);
+        }
+        Poll::Ready(item)
+    }
+}
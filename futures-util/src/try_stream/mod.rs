@@ -0,0 +1,166 @@
+//! Streams
+//!
+//! This module contains a number of functions for working with
+//! `Stream`s that return `Result`s, allowing for short-circuiting
+//! computations.
+
+use futures_core::future::TryFuture;
+use futures_core::stream::TryStream;
+
+mod try_filter_map;
+pub use self::try_filter_map::TryFilterMap;
+
+mod try_for_each_concurrent;
+pub use self::try_for_each_concurrent::TryForEachConcurrent;
+
+mod try_buffer_unordered;
+pub use self::try_buffer_unordered::TryBufferUnordered;
+
+mod inspect_ok;
+pub use self::inspect_ok::InspectOk;
+
+mod inspect_err;
+pub use self::inspect_err::InspectErr;
+
+mod try_skip_while;
+pub use self::try_skip_while::TrySkipWhile;
+
+mod and_then;
+pub use self::and_then::AndThen;
+
+mod or_else;
+pub use self::or_else::OrElse;
+
+impl<S: ?Sized> TryStreamExt for S where S: TryStream {}
+
+/// Adapters specific to `Result`-returning streams
+pub trait TryStreamExt: TryStream {
+    /// Attempts to filter the values produced by this stream according to
+    /// the provided asynchronous closure, mapping them to a different type
+    /// along the way.
+    ///
+    /// See [`StreamExt::filter_map`] for details.
+    fn try_filter_map<Fut, F, T>(self, f: F) -> TryFilterMap<Self, Fut, F>
+        where F: FnMut(Self::Ok) -> Fut,
+              Fut: TryFuture<Ok = Option<T>, Error = Self::Error>,
+              Self: Sized,
+    {
+        TryFilterMap::new(self, f)
+    }
+
+    /// Attempts to run this stream to completion, executing the provided
+    /// asynchronous closure for each `Ok` item, with up to `limit` futures
+    /// running concurrently.
+    ///
+    /// `limit` can be any value that can be converted into an
+    /// `Option<usize>`; a value of `None` means that there is no limit on
+    /// the number of futures that may run concurrently. A limit of one
+    /// means that futures are run sequentially. A limit of zero is treated
+    /// the same as `None`, since it would otherwise prevent any future
+    /// from ever being spawned.
+    ///
+    /// This future will drive the stream to keep producing items until it
+    /// is exhausted, or until the first time any spawned future or the
+    /// stream itself resolves to an `Err`, at which point this future
+    /// resolves to that same `Err`.
+    fn try_for_each_concurrent<Fut, F>(
+        self,
+        limit: impl Into<Option<usize>>,
+        f: F,
+    ) -> TryForEachConcurrent<Self, Fut, F>
+        where F: FnMut(Self::Ok) -> Fut,
+              Fut: TryFuture<Ok = (), Error = Self::Error>,
+              Self: Sized,
+    {
+        TryForEachConcurrent::new(self, limit.into(), f)
+    }
+
+    /// Attempts to execute several futures from a stream concurrently,
+    /// up to a limit.
+    ///
+    /// This stream's `Ok` items must themselves be futures, which this
+    /// adaptor will run concurrently, up to `n` at a time, yielding their
+    /// resolved `Ok` values in the order they complete (which may not be
+    /// the order they were produced in). An `Err` from either the source
+    /// stream or any of the spawned futures is yielded as an item; it does
+    /// not end the stream. A limit of zero is treated the same as an unbounded
+    /// limit, since it would otherwise prevent any future from ever being
+    /// spawned.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of
+    /// this library is activated, and it is activated by default.
+    fn try_buffer_unordered(self, n: usize) -> TryBufferUnordered<Self>
+        where Self::Ok: TryFuture<Error = Self::Error>,
+              Self: Sized,
+    {
+        TryBufferUnordered::new(self, n)
+    }
+
+    /// Do something with the `Ok` value of this stream, passing it on.
+    ///
+    /// When using a stream, you often want to inspect the `Ok` values it
+    /// produces without affecting the item itself. This method does
+    /// exactly that: it inspects the `Ok` value, leaves `Err` values
+    /// untouched, and then re-yields the item.
+    fn inspect_ok<F>(self, f: F) -> InspectOk<Self, F>
+        where F: FnMut(&Self::Ok),
+              Self: Sized,
+    {
+        InspectOk::new(self, f)
+    }
+
+    /// Do something with the `Err` value of this stream, passing it on.
+    ///
+    /// When using a stream, you often want to inspect the `Err` values it
+    /// produces without affecting the item itself. This method does
+    /// exactly that: it inspects the `Err` value, leaves `Ok` values
+    /// untouched, and then re-yields the item.
+    fn inspect_err<F>(self, f: F) -> InspectErr<Self, F>
+        where F: FnMut(&Self::Error),
+              Self: Sized,
+    {
+        InspectErr::new(self, f)
+    }
+
+    /// Skips elements on this stream while the provided asynchronous,
+    /// fallible predicate resolves to `true`.
+    ///
+    /// This method is similar to [`StreamExt::skip_while`], but an `Err`
+    /// from the predicate future or the stream itself is yielded right
+    /// away. The error does not end the stream: skipping goes on with the
+    /// next item, and an item whose predicate failed is dropped.
+    fn try_skip_while<Fut, F>(self, f: F) -> TrySkipWhile<Self, Fut, F>
+        where F: FnMut(&Self::Ok) -> Fut,
+              Fut: TryFuture<Ok = bool, Error = Self::Error>,
+              Self: Sized,
+    {
+        TrySkipWhile::new(self, f)
+    }
+
+    /// Executes an asynchronous, fallible transformation for each `Ok`
+    /// item of this stream.
+    ///
+    /// `Err` items of this stream are passed on without calling the
+    /// closure, and an `Err` from the closure's future is yielded in place
+    /// of the item. An error does not end the stream.
+    fn and_then<Fut, F>(self, f: F) -> AndThen<Self, Fut, F>
+        where F: FnMut(Self::Ok) -> Fut,
+              Fut: TryFuture<Error = Self::Error>,
+              Self: Sized,
+    {
+        AndThen::new(self, f)
+    }
+
+    /// Executes an asynchronous, fallible recovery for each `Err` item of
+    /// this stream, leaving `Ok` items untouched.
+    ///
+    /// The provided closure receives each error and returns a future that
+    /// may resolve to either a substitute `Ok` item or a new error.
+    fn or_else<Fut, F>(self, f: F) -> OrElse<Self, Fut, F>
+        where F: FnMut(Self::Error) -> Fut,
+              Fut: TryFuture<Ok = Self::Ok>,
+              Self: Sized,
+    {
+        OrElse::new(self, f)
+    }
+}
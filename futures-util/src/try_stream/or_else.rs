@@ -0,0 +1,111 @@
+use core::marker::Unpin;
+use core::mem::PinMut;
+use futures_core::future::TryFuture;
+use futures_core::stream::{FusedStream, Stream, TryStream};
+use futures_core::task::{self, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+
+/// A stream that runs a recovery future for each `Err` item, yielding
+/// either a substitute `Ok` item or a new error.
+///
+/// This structure is returned by the
+/// [`or_else`](super::TryStreamExt::or_else) method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct OrElse<St, Fut, F>
+    where St: TryStream,
+{
+    stream: St,
+    f: F,
+    pending: Option<Fut>,
+}
+
+impl<St, Fut, F> Unpin for OrElse<St, Fut, F>
+    where St: TryStream + Unpin, Fut: Unpin,
+{}
+
+impl<St, Fut, F> OrElse<St, Fut, F>
+    where St: TryStream,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_pinned!(pending: Option<Fut>);
+
+    pub(super) fn new(stream: St, f: F) -> Self {
+        OrElse { stream, f, pending: None }
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, Fut, F, Item> Sink<Item> for OrElse<S, Fut, F>
+    where S: TryStream + Sink<Item>,
+{
+    type SinkError = S::SinkError;
+
+    fn poll_ready(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.stream().poll_ready(cx)
+    }
+
+    fn start_send(
+        mut self: PinMut<Self>,
+        item: Item,
+    ) -> Result<(), Self::SinkError> {
+        self.stream().start_send(item)
+    }
+
+    fn poll_flush(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.stream().poll_flush(cx)
+    }
+
+    fn poll_close(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.stream().poll_close(cx)
+    }
+}
+
+impl<St, Fut, F> FusedStream for OrElse<St, Fut, F>
+    where St: TryStream + FusedStream,
+          F: FnMut(St::Error) -> Fut,
+          Fut: TryFuture<Ok = St::Ok>,
+{
+    fn is_terminated(&self) -> bool {
+        self.pending.is_none() && self.stream.is_terminated()
+    }
+}
+
+impl<St, Fut, F> Stream for OrElse<St, Fut, F>
+    where St: TryStream,
+          F: FnMut(St::Error) -> Fut,
+          Fut: TryFuture<Ok = St::Ok>,
+{
+    type Item = Result<St::Ok, Fut::Error>;
+
+    fn poll_next(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Option<Self::Item>> {
+        if self.pending().as_pin_mut().is_none() {
+            let item = match ready!(self.stream().try_poll_next(cx)) {
+                Some(Ok(x)) => return Poll::Ready(Some(Ok(x))),
+                Some(Err(e)) => e,
+                None => return Poll::Ready(None),
+            };
+            let fut = (self.f())(item);
+            PinMut::set(self.pending(), Some(fut));
+        }
+
+        let result = ready!(self.pending().as_pin_mut().unwrap().try_poll(cx));
+        PinMut::set(self.pending(), None);
+        Poll::Ready(Some(result))
+    }
+}
@@ -0,0 +1,96 @@
+use core::marker::Unpin;
+use core::mem::PinMut;
+use futures_core::future::TryFuture;
+use futures_core::stream::{FusedStream, Stream, TryStream};
+use futures_core::task::{self, Poll};
+
+use crate::future::{IntoFuture, TryFutureExt};
+use crate::stream::{Fuse, FuturesUnordered};
+
+/// A stream that runs the futures produced by the source stream
+/// concurrently, up to a limit, yielding their `Ok` results as they
+/// complete.
+///
+/// This structure is returned by the
+/// [`try_buffer_unordered`](super::TryStreamExt::try_buffer_unordered)
+/// method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct TryBufferUnordered<St>
+    where St: TryStream,
+          St::Ok: TryFuture<Error = St::Error>,
+{
+    stream: Fuse<St>,
+    in_progress_queue: FuturesUnordered<IntoFuture<St::Ok>>,
+    max: usize,
+}
+
+impl<St> Unpin for TryBufferUnordered<St>
+    where St: TryStream + Unpin,
+          St::Ok: TryFuture<Error = St::Error>,
+{}
+
+impl<St> TryBufferUnordered<St>
+    where St: TryStream,
+          St::Ok: TryFuture<Error = St::Error>,
+{
+    unsafe_pinned!(stream: Fuse<St>);
+    unsafe_pinned!(in_progress_queue: FuturesUnordered<IntoFuture<St::Ok>>);
+
+    pub(super) fn new(stream: St, n: usize) -> Self {
+        TryBufferUnordered {
+            stream: Fuse::new(stream),
+            in_progress_queue: FuturesUnordered::new(),
+            // A limit of zero would mean nothing is ever allowed into the
+            // in-progress queue, so the stream would never be polled at
+            // all; treat it the same as "unbounded" instead, matching
+            // try_for_each_concurrent.
+            max: if n == 0 { usize::max_value() } else { n },
+        }
+    }
+}
+
+impl<St> FusedStream for TryBufferUnordered<St>
+    where St: TryStream,
+          St::Ok: TryFuture<Error = St::Error>,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_done() && self.in_progress_queue.is_empty()
+    }
+}
+
+impl<St> Stream for TryBufferUnordered<St>
+    where St: TryStream,
+          St::Ok: TryFuture<Error = St::Error>,
+{
+    type Item = Result<<St::Ok as TryFuture>::Ok, St::Error>;
+
+    fn poll_next(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Option<Self::Item>> {
+        // First, try to spawn off as many futures as possible by filling up
+        // the in-progress queue.
+        while self.in_progress_queue.len() < self.max {
+            match self.stream().try_poll_next(cx) {
+                Poll::Ready(Some(Ok(fut))) => self.in_progress_queue().push(fut.into_future()),
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                Poll::Ready(None) | Poll::Pending => break,
+            }
+        }
+
+        // Attempt to pull the next value from the in_progress_queue.
+        let res = self.in_progress_queue().poll_next(cx);
+        if let Some(val) = ready!(res) {
+            return Poll::Ready(Some(val));
+        }
+
+        // If more values are still coming from the stream, we're not done
+        // yet.
+        if self.stream.is_done() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
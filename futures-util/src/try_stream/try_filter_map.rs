@@ -1,8 +1,10 @@
 use core::marker::Unpin;
 use core::mem::PinMut;
 use futures_core::future::{TryFuture};
-use futures_core::stream::{Stream, TryStream};
+use futures_core::stream::{FusedStream, Stream, TryStream};
 use futures_core::task::{self, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
 
 /// A combinator that attempts to filter the results of a stream
 /// and simultaneously map them to a different type.
@@ -54,6 +56,52 @@ impl<St, Fut, F> TryFilterMap<St, Fut, F> {
     }
 }
 
+impl<St, Fut, F, T> FusedStream for TryFilterMap<St, Fut, F>
+    where St: TryStream + FusedStream,
+          Fut: TryFuture<Ok = Option<T>, Error = St::Error>,
+          F: FnMut(St::Ok) -> Fut,
+{
+    fn is_terminated(&self) -> bool {
+        self.pending.is_none() && self.stream.is_terminated()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, Fut, F, Item> Sink<Item> for TryFilterMap<S, Fut, F>
+    where S: TryStream + Sink<Item>,
+{
+    type SinkError = S::SinkError;
+
+    fn poll_ready(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.stream().poll_ready(cx)
+    }
+
+    fn start_send(
+        mut self: PinMut<Self>,
+        item: Item,
+    ) -> Result<(), Self::SinkError> {
+        self.stream().start_send(item)
+    }
+
+    fn poll_flush(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.stream().poll_flush(cx)
+    }
+
+    fn poll_close(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.stream().poll_close(cx)
+    }
+}
+
 impl<St, Fut, F, T> Stream for TryFilterMap<St, Fut, F>
     where St: TryStream,
           Fut: TryFuture<Ok = Option<T>, Error = St::Error>,
@@ -0,0 +1,110 @@
+use core::marker::Unpin;
+use core::mem::PinMut;
+use futures_core::future::{Future, TryFuture};
+use futures_core::stream::{Stream, TryStream};
+use futures_core::task::{self, Poll};
+
+use crate::future::{IntoFuture, TryFutureExt};
+use crate::stream::FuturesUnordered;
+
+/// A future that attempts to run `Ok` items of a stream concurrently
+/// while limiting the number of concurrently running futures, and
+/// short-circuits on the first error.
+///
+/// This structure is returned by the
+/// [`try_for_each_concurrent`](super::TryStreamExt::try_for_each_concurrent)
+/// method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct TryForEachConcurrent<St, Fut, F> {
+    stream: Option<St>,
+    f: F,
+    futures: FuturesUnordered<IntoFuture<Fut>>,
+    limit: Option<usize>,
+}
+
+impl<St, Fut, F> Unpin for TryForEachConcurrent<St, Fut, F>
+    where St: Unpin, Fut: Unpin,
+{}
+
+impl<St, Fut, F> TryForEachConcurrent<St, Fut, F>
+    where St: TryStream,
+          F: FnMut(St::Ok) -> Fut,
+          Fut: TryFuture<Ok = (), Error = St::Error>,
+{
+    unsafe_pinned!(stream: Option<St>);
+    unsafe_unpinned!(f: F);
+    unsafe_pinned!(futures: FuturesUnordered<IntoFuture<Fut>>);
+
+    pub(super) fn new(stream: St, limit: Option<usize>, f: F) -> Self {
+        TryForEachConcurrent {
+            stream: Some(stream),
+            f,
+            futures: FuturesUnordered::new(),
+            // A limit of zero effectively means "no limit", since it would
+            // otherwise prevent any future from ever being spawned.
+            limit: limit.and_then(|limit| if limit == 0 { None } else { Some(limit) }),
+        }
+    }
+}
+
+impl<St, Fut, F> Future for TryForEachConcurrent<St, Fut, F>
+    where St: TryStream,
+          F: FnMut(St::Ok) -> Fut,
+          Fut: TryFuture<Ok = (), Error = St::Error>,
+{
+    type Output = Result<(), St::Error>;
+
+    fn poll(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Self::Output> {
+        loop {
+            let mut made_progress_this_iter = false;
+
+            // Check if we've already created `limit` futures or more, if
+            // not, poll the stream for more work to do.
+            if self.limit.map(|limit| limit > self.futures.len()).unwrap_or(true) {
+                let mut stream_completed = false;
+                let elem = if let Some(stream) = self.stream().as_pin_mut() {
+                    match stream.try_poll_next(cx) {
+                        Poll::Ready(Some(Ok(elem))) => {
+                            made_progress_this_iter = true;
+                            Some(elem)
+                        }
+                        Poll::Ready(Some(Err(e))) => return Poll::Ready(Err(e)),
+                        Poll::Ready(None) => {
+                            stream_completed = true;
+                            None
+                        }
+                        Poll::Pending => None,
+                    }
+                } else {
+                    None
+                };
+                if stream_completed {
+                    PinMut::set(self.stream(), None);
+                }
+                if let Some(elem) = elem {
+                    let next_future = (self.f())(elem);
+                    self.futures().push(next_future.into_future());
+                }
+            }
+
+            match self.futures().poll_next(cx) {
+                Poll::Ready(Some(Ok(()))) => made_progress_this_iter = true,
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Err(e)),
+                Poll::Ready(None) => {
+                    if self.stream.is_none() {
+                        return Poll::Ready(Ok(()));
+                    }
+                }
+                Poll::Pending => {}
+            }
+
+            if !made_progress_this_iter {
+                return Poll::Pending;
+            }
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use core::marker::Unpin;
+use core::mem::PinMut;
+use futures_core::future::TryFuture;
+use futures_core::stream::{FusedStream, Stream, TryStream};
+use futures_core::task::{self, Poll};
+#[cfg(feature = "sink")]
+use futures_sink::Sink;
+
+/// A stream that skips elements of another stream while a predicate holds,
+/// using an asynchronous, fallible predicate.
+///
+/// This structure is returned by the
+/// [`try_skip_while`](super::TryStreamExt::try_skip_while) method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct TrySkipWhile<St, Fut, F>
+    where St: TryStream,
+{
+    stream: St,
+    f: F,
+    pending_fut: Option<Fut>,
+    pending_item: Option<St::Ok>,
+    done_skipping: bool,
+}
+
+impl<St, Fut, F> Unpin for TrySkipWhile<St, Fut, F>
+    where St: TryStream + Unpin, St::Ok: Unpin, Fut: Unpin,
+{}
+
+impl<St, Fut, F> TrySkipWhile<St, Fut, F>
+    where St: TryStream,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_pinned!(pending_fut: Option<Fut>);
+    unsafe_unpinned!(pending_item: Option<St::Ok>);
+    unsafe_unpinned!(done_skipping: bool);
+
+    pub(super) fn new(stream: St, f: F) -> Self {
+        TrySkipWhile {
+            stream,
+            f,
+            pending_fut: None,
+            pending_item: None,
+            done_skipping: false,
+        }
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+#[cfg(feature = "sink")]
+impl<S, Fut, F, Item> Sink<Item> for TrySkipWhile<S, Fut, F>
+    where S: TryStream + Sink<Item>,
+{
+    type SinkError = S::SinkError;
+
+    fn poll_ready(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.stream().poll_ready(cx)
+    }
+
+    fn start_send(
+        mut self: PinMut<Self>,
+        item: Item,
+    ) -> Result<(), Self::SinkError> {
+        self.stream().start_send(item)
+    }
+
+    fn poll_flush(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.stream().poll_flush(cx)
+    }
+
+    fn poll_close(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.stream().poll_close(cx)
+    }
+}
+
+impl<St, Fut, F> FusedStream for TrySkipWhile<St, Fut, F>
+    where St: TryStream + FusedStream,
+          F: FnMut(&St::Ok) -> Fut,
+          Fut: TryFuture<Ok = bool, Error = St::Error>,
+{
+    fn is_terminated(&self) -> bool {
+        self.pending_fut.is_none() && self.stream.is_terminated()
+    }
+}
+
+impl<St, Fut, F> Stream for TrySkipWhile<St, Fut, F>
+    where St: TryStream,
+          F: FnMut(&St::Ok) -> Fut,
+          Fut: TryFuture<Ok = bool, Error = St::Error>,
+{
+    type Item = Result<St::Ok, St::Error>;
+
+    fn poll_next(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Option<Self::Item>> {
+        if self.done_skipping {
+            return self.stream().try_poll_next(cx);
+        }
+
+        loop {
+            if self.pending_fut().as_pin_mut().is_none() {
+                let item = match ready!(self.stream().try_poll_next(cx)) {
+                    Some(Ok(x)) => x,
+                    Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+                    None => return Poll::Ready(None),
+                };
+                let fut = (self.f())(&item);
+                PinMut::set(self.pending_fut(), Some(fut));
+                *self.pending_item() = Some(item);
+            }
+
+            let skipped = match ready!(self.pending_fut().as_pin_mut().unwrap().try_poll(cx)) {
+                Ok(x) => x,
+                Err(e) => {
+                    PinMut::set(self.pending_fut(), None);
+                    self.pending_item().take();
+                    return Poll::Ready(Some(Err(e)));
+                }
+            };
+            PinMut::set(self.pending_fut(), None);
+            let item = self.pending_item().take().unwrap();
+
+            if !skipped {
+                *self.done_skipping() = true;
+                return Poll::Ready(Some(Ok(item)));
+            }
+        }
+    }
+}